@@ -21,6 +21,9 @@ extern crate serde;
 #[cfg(feature = "serde_support")]
 use serde::{Deserialize, Serialize};
 
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
 /// 2D coordinate
 #[cfg_attr(feature = "serde_support", derive(Serialize, Deserialize))]
 #[derive(Clone, Copy, Debug, Hash, Eq, PartialEq)]
@@ -171,6 +174,51 @@ impl<Elem: Clone> Vec2D<Elem> {
         self.elems.resize(new_size.area(), value);
         self.size = new_size;
     }
+
+    /// Split into `n` stacked strips of equal height, each copied into
+    /// its own Vec2D. If the height is not divisible by `n` the last
+    /// strip absorbs the remaining rows. An empty Vec is returned if
+    /// `n` is zero.
+    pub fn horizontal_strips(&self, n: usize) -> Vec<Vec2D<Elem>> {
+        if n == 0 {
+            return Vec::new();
+        }
+        let width = self.size.width;
+        let strip_height = self.size.height / n;
+        (0..n)
+            .map(|i| {
+                let start = i * strip_height;
+                let end = if i == n - 1 {
+                    self.size.height
+                } else {
+                    start + strip_height
+                };
+                Vec2D {
+                    elems: self.elems[start * width..end * width].to_vec(),
+                    size: Size::new(width, end - start),
+                }
+            })
+            .collect()
+    }
+
+    /// Stack `strips` vertically into a single Vec2D. None is
+    /// returned if `strips` is empty or if the strips do not all
+    /// have the same width.
+    pub fn from_strips(strips: &[Vec2D<Elem>]) -> Option<Vec2D<Elem>> {
+        let width = strips.first()?.size.width;
+        if strips.iter().any(|strip| strip.size.width != width) {
+            return None;
+        }
+        let height = strips.iter().map(|strip| strip.size.height).sum();
+        let mut elems = Vec::with_capacity(width * height);
+        for strip in strips {
+            elems.extend_from_slice(&strip.elems);
+        }
+        Some(Vec2D {
+            elems,
+            size: Size::new(width, height),
+        })
+    }
 }
 
 impl<Elem> Vec2D<Elem> {
@@ -317,6 +365,410 @@ impl<Elem> Vec2D<Elem> {
             None
         }
     }
+
+    /// Hash the size and all elements into a single `u64`. The
+    /// result is deterministic within a run, so it can be used to
+    /// cheaply detect whether the Vec2D has changed.
+    pub fn checksum(&self) -> u64
+    where
+        Elem: Hash,
+    {
+        let mut hasher = DefaultHasher::new();
+        self.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    /// Iterator over the cells where `x - y == diag`, in order of
+    /// increasing coordinates. Diagonal 0 is the main diagonal and
+    /// positive values are above and to the right of it. An
+    /// out-of-range diagonal yields nothing.
+    pub fn diagonal_cells(&self, diag: isize) -> impl Iterator<Item = (Coord, &Elem)> + '_ {
+        let start = if diag >= 0 {
+            Coord::new(diag as usize, 0)
+        } else {
+            Coord::new(0, diag.unsigned_abs())
+        };
+        (0..)
+            .map(move |i| start + Coord::new(i, i))
+            .map_while(move |coord| self.get(coord).map(|elem| (coord, elem)))
+    }
+
+    /// Create a coarse boolean Vec2D where each output cell is true if
+    /// any element in the corresponding `factor`x`factor` block
+    /// satisfies `pred`. If the size is not divisible by `factor` the
+    /// blocks along the right and bottom edges are partial.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `factor` is zero.
+    pub fn downscale_any<F: Fn(&Elem) -> bool>(&self, factor: usize, pred: F) -> Vec2D<bool> {
+        let size = Size::new(
+            self.size.width.div_ceil(factor),
+            self.size.height.div_ceil(factor),
+        );
+        let mut out = Vec2D::from_example(size, &false);
+        for (i, elem) in self.elems.iter().enumerate() {
+            if pred(elem) {
+                let x = i % self.size.width / factor;
+                let y = i / self.size.width / factor;
+                out.elems[y * size.width + x] = true;
+            }
+        }
+        out
+    }
+
+    /// Calculate a summed-area table with an extra leading row and
+    /// column of zeros, so it has `(width + 1) * (height + 1)`
+    /// entries.
+    fn summed_area_table(&self) -> Vec<u64>
+    where
+        Elem: Into<u64> + Copy,
+    {
+        let table_width = self.size.width + 1;
+        let mut table = vec![0; table_width * (self.size.height + 1)];
+        for (i, &elem) in self.elems.iter().enumerate() {
+            let x = i % self.size.width + 1;
+            let y = i / self.size.width + 1;
+            table[y * table_width + x] =
+                elem.into() + table[(y - 1) * table_width + x] + table[y * table_width + x - 1]
+                    - table[(y - 1) * table_width + x - 1];
+        }
+        table
+    }
+
+    /// Create a Vec2D of the sums over every `window`-sized
+    /// rectangle. The output cell at (x, y) holds the sum of the
+    /// window whose top-left corner is (x, y), so the output size is
+    /// `(width - window.width + 1, height - window.height + 1)`. If
+    /// `window` does not fit within the Vec2D the result is empty.
+    pub fn window_sums(&self, window: Size) -> Vec2D<u64>
+    where
+        Elem: Into<u64> + Copy,
+    {
+        if window.width > self.size.width || window.height > self.size.height {
+            return Vec2D {
+                elems: Vec::new(),
+                size: Size::new(0, 0),
+            };
+        }
+
+        let table = self.summed_area_table();
+        let table_width = self.size.width + 1;
+        let size = Size::new(
+            self.size.width - window.width + 1,
+            self.size.height - window.height + 1,
+        );
+        let mut elems = Vec::with_capacity(size.area());
+        for y in 0..size.height {
+            for x in 0..size.width {
+                let (x1, y1) = (x + window.width, y + window.height);
+                elems.push(
+                    table[y1 * table_width + x1] + table[y * table_width + x]
+                        - table[y * table_width + x1]
+                        - table[y1 * table_width + x],
+                );
+            }
+        }
+        Vec2D { elems, size }
+    }
+
+    /// Reflect a possibly out-of-bounds coordinate back into the
+    /// Vec2D, mirroring about the edge cells without repeating them
+    /// (reflect-101 style), so `-1` maps to `1` and `width` maps to
+    /// `width - 2`. None is returned if the Vec2D is empty.
+    pub fn reflect_coord(&self, x: isize, y: isize) -> Option<Coord> {
+        if self.size.area() == 0 {
+            return None;
+        }
+        Some(Coord::new(
+            reflect_101(x, self.size.width),
+            reflect_101(y, self.size.height),
+        ))
+    }
+
+    /// Label each element with the ID of the 4-connected component of
+    /// equal elements it belongs to. IDs are assigned in row-major
+    /// order of each component's first element. Returns the labels
+    /// (indexed like `elems`) and the number of components.
+    fn component_labels(&self) -> (Vec<usize>, usize)
+    where
+        Elem: Eq,
+    {
+        let width = self.size.width;
+        let mut labels = vec![usize::MAX; self.elems.len()];
+        let mut num_components = 0;
+        let mut stack = Vec::new();
+        for start in 0..self.elems.len() {
+            if labels[start] != usize::MAX {
+                continue;
+            }
+            labels[start] = num_components;
+            stack.push(start);
+            while let Some(i) = stack.pop() {
+                let (x, y) = (i % width, i / width);
+                let mut neighbors = Vec::with_capacity(4);
+                if x > 0 {
+                    neighbors.push(i - 1);
+                }
+                if x + 1 < width {
+                    neighbors.push(i + 1);
+                }
+                if y > 0 {
+                    neighbors.push(i - width);
+                }
+                if y + 1 < self.size.height {
+                    neighbors.push(i + width);
+                }
+                for n in neighbors {
+                    if labels[n] == usize::MAX && self.elems[n] == self.elems[start] {
+                        labels[n] = num_components;
+                        stack.push(n);
+                    }
+                }
+            }
+            num_components += 1;
+        }
+        (labels, num_components)
+    }
+
+    /// Calculate the bounding rectangle of each 4-connected component
+    /// of equal elements. The result is indexed by component ID, with
+    /// IDs assigned in row-major order of each component's first
+    /// element.
+    pub fn component_bounds(&self) -> Vec<Rect>
+    where
+        Elem: Eq,
+    {
+        let (labels, num_components) = self.component_labels();
+        let mut bounds: Vec<Option<Rect>> = vec![None; num_components];
+        for (i, &label) in labels.iter().enumerate() {
+            let coord = Coord::new(i % self.size.width, i / self.size.width);
+            bounds[label] = Some(match bounds[label] {
+                None => Rect {
+                    min_coord: coord,
+                    max_coord: coord,
+                },
+                Some(rect) => Rect {
+                    min_coord: Coord::new(rect.min_coord.x.min(coord.x), rect.min_coord.y),
+                    max_coord: Coord::new(rect.max_coord.x.max(coord.x), coord.y),
+                },
+            });
+        }
+        bounds.into_iter().flatten().collect()
+    }
+
+    /// Get the element at (x, y) with the coordinate clamped to the
+    /// nearest edge. The Vec2D must not be empty.
+    fn get_clamped(&self, x: isize, y: isize) -> &Elem {
+        let x = x.clamp(0, self.size.width as isize - 1) as usize;
+        let y = y.clamp(0, self.size.height as isize - 1) as usize;
+        &self.elems[y * self.size.width + x]
+    }
+
+    /// Create a Vec2D where each element is replaced by the median of
+    /// its `(2 * radius + 1)`x`(2 * radius + 1)` neighborhood.
+    /// Coordinates outside the Vec2D are clamped to the nearest edge.
+    /// If the neighborhood has an even number of elements the lower
+    /// median is used.
+    pub fn median_filter(&self, radius: usize) -> Vec2D<Elem>
+    where
+        Elem: Ord + Clone,
+    {
+        let r = radius as isize;
+        let mut window = Vec::with_capacity((2 * radius + 1) * (2 * radius + 1));
+        let elems = (0..self.elems.len())
+            .map(|i| {
+                let x = (i % self.size.width) as isize;
+                let y = (i / self.size.width) as isize;
+                window.clear();
+                for dy in -r..=r {
+                    for dx in -r..=r {
+                        window.push(self.get_clamped(x + dx, y + dy));
+                    }
+                }
+                let mid = (window.len() - 1) / 2;
+                window.select_nth_unstable(mid).1.clone()
+            })
+            .collect();
+        Vec2D {
+            elems,
+            size: self.size,
+        }
+    }
+
+    /// Cyclically shift the elements of row `y` by `by` places. A
+    /// positive `by` moves elements towards higher x, wrapping around
+    /// to the start of the row. Returns false if `y` is out of range.
+    pub fn roll_row(&mut self, y: usize, by: isize) -> bool {
+        if y >= self.size.height {
+            return false;
+        }
+        let width = self.size.width;
+        if width > 0 {
+            let row = &mut self.elems[y * width..(y + 1) * width];
+            row.rotate_right(by.rem_euclid(width as isize) as usize);
+        }
+        true
+    }
+
+    /// Cyclically shift the elements of column `x` by `by` places. A
+    /// positive `by` moves elements towards higher y, wrapping around
+    /// to the top of the column. Returns false if `x` is out of range.
+    pub fn roll_col(&mut self, x: usize, by: isize) -> bool {
+        if x >= self.size.width {
+            return false;
+        }
+        let height = self.size.height;
+        if height > 0 {
+            // Rotate right by reversing the whole column, then
+            // reversing each of the two parts
+            let k = by.rem_euclid(height as isize) as usize;
+            self.reverse_col(x, 0, height);
+            self.reverse_col(x, 0, k);
+            self.reverse_col(x, k, height);
+        }
+        true
+    }
+
+    /// Reverse the elements of column `x` in the row range
+    /// `start..end`.
+    fn reverse_col(&mut self, x: usize, start: usize, end: usize) {
+        let width = self.size.width;
+        let (mut top, mut bottom) = (start, end);
+        while top + 1 < bottom {
+            bottom -= 1;
+            self.elems.swap(top * width + x, bottom * width + x);
+            top += 1;
+        }
+    }
+
+    /// Create a Vec2D of the direction of the Sobel gradient at each
+    /// element, as `atan2(dy, dx)` in radians. Coordinates outside the
+    /// Vec2D are clamped to the nearest edge. Elements where the
+    /// gradient is zero have a direction of 0.0.
+    pub fn gradient_direction(&self) -> Vec2D<f32>
+    where
+        Elem: Into<f32> + Copy,
+    {
+        let elems = (0..self.elems.len())
+            .map(|i| {
+                let x = (i % self.size.width) as isize;
+                let y = (i / self.size.width) as isize;
+                let p =
+                    |dx: isize, dy: isize| -> f32 { (*self.get_clamped(x + dx, y + dy)).into() };
+                let dx =
+                    (p(1, -1) + 2.0 * p(1, 0) + p(1, 1)) - (p(-1, -1) + 2.0 * p(-1, 0) + p(-1, 1));
+                let dy =
+                    (p(-1, 1) + 2.0 * p(0, 1) + p(1, 1)) - (p(-1, -1) + 2.0 * p(0, -1) + p(1, -1));
+                if dx == 0.0 && dy == 0.0 {
+                    0.0
+                } else {
+                    dy.atan2(dx)
+                }
+            })
+            .collect();
+        Vec2D {
+            elems,
+            size: self.size,
+        }
+    }
+
+    /// Find the top-left coordinate where `needle` appears as an
+    /// exact sub-region of the Vec2D. If there are multiple matches
+    /// the first in row-major order is returned. None is returned if
+    /// there is no match.
+    ///
+    /// This is a naive search that compares every candidate position,
+    /// so it takes `O(W * H * w * h)` time in the worst case, where
+    /// `W`x`H` is the size of `self` and `w`x`h` is the size of
+    /// `needle`.
+    pub fn find_subgrid(&self, needle: &Vec2D<Elem>) -> Option<Coord>
+    where
+        Elem: PartialEq,
+    {
+        let (width, height) = (self.size.width, self.size.height);
+        let (needle_width, needle_height) = (needle.size.width, needle.size.height);
+        if needle_width > width || needle_height > height {
+            return None;
+        }
+        for y in 0..=height - needle_height {
+            for x in 0..=width - needle_width {
+                let matches = (0..needle_height).all(|ny| {
+                    let start = (y + ny) * width + x;
+                    let needle_start = ny * needle_width;
+                    self.elems[start..start + needle_width]
+                        == needle.elems[needle_start..needle_start + needle_width]
+                });
+                if matches {
+                    return Some(Coord::new(x, y));
+                }
+            }
+        }
+        None
+    }
+
+    /// Count how many of the eight neighbors of `coord` satisfy
+    /// `pred`. Neighbors outside the Vec2D are not counted.
+    pub fn count_neighbors8<F: Fn(&Elem) -> bool>(&self, coord: Coord, pred: F) -> usize {
+        let mut count = 0;
+        for dy in -1..=1 {
+            for dx in -1..=1 {
+                if dx == 0 && dy == 0 {
+                    continue;
+                }
+                let neighbor = match (
+                    coord.x.checked_add_signed(dx),
+                    coord.y.checked_add_signed(dy),
+                ) {
+                    (Some(x), Some(y)) => self.get(Coord::new(x, y)),
+                    _ => None,
+                };
+                if neighbor.is_some_and(&pred) {
+                    count += 1;
+                }
+            }
+        }
+        count
+    }
+}
+
+/// Reflect `i` into the range `0..len` without repeating the edge
+/// values. `len` must be non-zero.
+fn reflect_101(i: isize, len: usize) -> usize {
+    if len == 1 {
+        return 0;
+    }
+    let period = 2 * (len as isize - 1);
+    let i = i.rem_euclid(period) as usize;
+    if i < len {
+        i
+    } else {
+        period as usize - i
+    }
+}
+
+impl Vec2D<bool> {
+    /// Create the next generation of Conway's Game of Life, treating
+    /// `true` as alive. The standard B3/S23 rules are applied: a dead
+    /// cell with exactly three live neighbors is born, and a live cell
+    /// with two or three live neighbors survives. Cells outside the
+    /// Vec2D count as dead.
+    pub fn life_step(&self) -> Vec2D<bool> {
+        let elems = self
+            .elems
+            .iter()
+            .enumerate()
+            .map(|(i, &alive)| {
+                let coord = Coord::new(i % self.size.width, i / self.size.width);
+                let neighbors = self.count_neighbors8(coord, |&n| n);
+                neighbors == 3 || (alive && neighbors == 2)
+            })
+            .collect();
+        Vec2D {
+            elems,
+            size: self.size,
+        }
+    }
 }
 
 impl<'a, Elem> Iterator for RectIter<'a, Elem> {
@@ -530,4 +982,242 @@ mod test {
         assert_eq!((coord, *elem), (start, 1));
         assert_eq!(iter.next().is_none(), true);
     }
+
+    #[test]
+    fn test_checksum() {
+        let mut v = Vec2D::from_vec(Size::new(2, 2), vec![1, 2, 3, 4]).unwrap();
+        let before = v.checksum();
+        assert_eq!(v.checksum(), before);
+
+        *v.get_mut(Coord::new(1, 1)).unwrap() = 5;
+        assert_ne!(v.checksum(), before);
+    }
+
+    #[test]
+    fn test_diagonal_cells() {
+        let v = Vec2D::from_vec(Size::new(3, 3), (0..9).collect()).unwrap();
+
+        let above: Vec<_> = v.diagonal_cells(1).collect();
+        assert_eq!(above, [(Coord::new(1, 0), &1), (Coord::new(2, 1), &5)]);
+
+        let below: Vec<_> = v.diagonal_cells(-1).collect();
+        assert_eq!(below, [(Coord::new(0, 1), &3), (Coord::new(1, 2), &7)]);
+
+        assert_eq!(v.diagonal_cells(3).count(), 0);
+        assert_eq!(v.diagonal_cells(-3).count(), 0);
+    }
+
+    #[test]
+    fn test_downscale_any() {
+        let mut v = Vec2D::from_example(Size::new(4, 4), &0);
+        *v.get_mut(Coord::new(3, 0)).unwrap() = 1;
+
+        let coarse = v.downscale_any(2, |&e| e == 1);
+        assert_eq!(coarse.size(), Size::new(2, 2));
+        assert_eq!(coarse.elems, [false, true, false, false]);
+
+        let partial = v.downscale_any(3, |&e| e == 1);
+        assert_eq!(partial.size(), Size::new(2, 2));
+        assert_eq!(partial.elems, [false, true, false, false]);
+    }
+
+    #[test]
+    fn test_window_sums() {
+        let v = Vec2D::from_vec(Size::new(4, 3), (0u8..12).collect()).unwrap();
+        let window = Size::new(2, 2);
+        let sums = v.window_sums(window);
+        assert_eq!(sums.size(), Size::new(3, 2));
+
+        for (coord, &sum) in sums.iter() {
+            let rect = Rect::new(coord, coord + Coord::new(1, 1)).unwrap();
+            let expected: u64 = v.rect_iter(rect).unwrap().map(|(_, &e)| e as u64).sum();
+            assert_eq!(sum, expected);
+        }
+
+        assert_eq!(v.window_sums(Size::new(5, 1)).size(), Size::new(0, 0));
+    }
+
+    #[test]
+    fn test_reflect_coord() {
+        let v = Vec2D::from_example(Size::new(3, 3), &0);
+        assert_eq!(v.reflect_coord(-1, -1), Some(Coord::new(1, 1)));
+        assert_eq!(v.reflect_coord(3, 3), Some(Coord::new(1, 1)));
+        assert_eq!(v.reflect_coord(-2, 4), Some(Coord::new(2, 0)));
+        assert_eq!(v.reflect_coord(2, 0), Some(Coord::new(2, 0)));
+
+        let empty: Vec2D<i32> = Vec2D::from_vec(Size::new(0, 0), Vec::new()).unwrap();
+        assert_eq!(empty.reflect_coord(0, 0), None);
+    }
+
+    #[test]
+    fn test_horizontal_strips() {
+        let v = Vec2D::from_vec(Size::new(3, 4), (0..12).collect()).unwrap();
+
+        let strips = v.horizontal_strips(2);
+        assert_eq!(strips.len(), 2);
+        assert_eq!(strips[0].size(), Size::new(3, 2));
+        assert_eq!(strips[1].size(), Size::new(3, 2));
+        assert_eq!(strips[0].elems, [0, 1, 2, 3, 4, 5]);
+        assert_eq!(strips[1].elems, [6, 7, 8, 9, 10, 11]);
+
+        let strips = v.horizontal_strips(3);
+        assert_eq!(strips[0].size(), Size::new(3, 1));
+        assert_eq!(strips[1].size(), Size::new(3, 1));
+        assert_eq!(strips[2].size(), Size::new(3, 2));
+    }
+
+    #[test]
+    fn test_from_strips() {
+        let v = Vec2D::from_vec(Size::new(3, 4), (0..12).collect()).unwrap();
+        let strips = v.horizontal_strips(2);
+        assert_eq!(Vec2D::from_strips(&strips), Some(v));
+
+        let narrow = Vec2D::from_example(Size::new(2, 1), &0);
+        assert_eq!(Vec2D::from_strips(&[strips[0].clone(), narrow]), None);
+        assert_eq!(Vec2D::<i32>::from_strips(&[]), None);
+    }
+
+    #[test]
+    fn test_component_bounds() {
+        #[rustfmt::skip]
+        let v = Vec2D::from_vec(Size::new(5, 4), vec![
+            1, 1, 0, 0, 0,
+            0, 1, 0, 0, 0,
+            0, 0, 0, 2, 2,
+            0, 0, 0, 2, 0,
+        ]).unwrap();
+
+        let bounds = v.component_bounds();
+        assert_eq!(bounds.len(), 4);
+        assert_eq!(
+            bounds[0],
+            Rect::new(Coord::new(0, 0), Coord::new(1, 1)).unwrap()
+        );
+        assert_eq!(
+            bounds[1],
+            Rect::new(Coord::new(0, 0), Coord::new(4, 3)).unwrap()
+        );
+        assert_eq!(
+            bounds[2],
+            Rect::new(Coord::new(3, 2), Coord::new(4, 3)).unwrap()
+        );
+        assert_eq!(
+            bounds[3],
+            Rect::new(Coord::new(4, 3), Coord::new(4, 3)).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_median_filter() {
+        let mut v = Vec2D::from_example(Size::new(4, 4), &10);
+        *v.get_mut(Coord::new(1, 2)).unwrap() = 255;
+
+        let filtered = v.median_filter(1);
+        assert_eq!(filtered, Vec2D::from_example(Size::new(4, 4), &10));
+        assert_eq!(v.median_filter(0), v);
+    }
+
+    #[test]
+    fn test_roll_row() {
+        let mut v = Vec2D::from_vec(Size::new(3, 3), (0..9).collect()).unwrap();
+        assert!(v.roll_row(1, 1));
+        assert_eq!(v.elems, [0, 1, 2, 5, 3, 4, 6, 7, 8]);
+
+        assert!(v.roll_row(1, -1));
+        assert_eq!(v.elems, [0, 1, 2, 3, 4, 5, 6, 7, 8]);
+
+        assert!(!v.roll_row(3, 1));
+    }
+
+    #[test]
+    fn test_roll_col() {
+        let mut v = Vec2D::from_vec(Size::new(3, 3), (0..9).collect()).unwrap();
+        assert!(v.roll_col(2, 1));
+        assert_eq!(v.elems, [0, 1, 8, 3, 4, 2, 6, 7, 5]);
+
+        assert!(v.roll_col(2, 5));
+        assert_eq!(v.elems, [0, 1, 2, 3, 4, 5, 6, 7, 8]);
+
+        assert!(!v.roll_col(3, 1));
+    }
+
+    #[test]
+    fn test_gradient_direction() {
+        #[rustfmt::skip]
+        let v = Vec2D::from_vec(Size::new(4, 3), vec![
+            0u8, 0, 9, 9,
+            0, 0, 9, 9,
+            0, 0, 9, 9,
+        ]).unwrap();
+
+        let directions = v.gradient_direction();
+        assert_eq!(directions.size(), v.size());
+        for y in 0..3 {
+            for x in 1..3 {
+                let d = *directions.get(Coord::new(x, y)).unwrap();
+                assert!(d.abs() < 1e-6);
+            }
+        }
+
+        let flat = Vec2D::from_example(Size::new(2, 2), &1u8);
+        assert_eq!(flat.gradient_direction().elems, [0.0; 4]);
+    }
+
+    #[test]
+    fn test_find_subgrid() {
+        let v = Vec2D::from_vec(Size::new(4, 4), (0..16).collect()).unwrap();
+
+        let needle = Vec2D::from_vec(Size::new(2, 2), vec![6, 7, 10, 11]).unwrap();
+        assert_eq!(v.find_subgrid(&needle), Some(Coord::new(2, 1)));
+
+        let missing = Vec2D::from_vec(Size::new(2, 2), vec![6, 7, 11, 10]).unwrap();
+        assert_eq!(v.find_subgrid(&missing), None);
+
+        let too_big = Vec2D::from_example(Size::new(5, 1), &0);
+        assert_eq!(v.find_subgrid(&too_big), None);
+    }
+
+    #[test]
+    fn test_count_neighbors8() {
+        #[rustfmt::skip]
+        let v = Vec2D::from_vec(Size::new(3, 3), vec![
+            true, true, false,
+            true, false, true,
+            false, true, true,
+        ]).unwrap();
+
+        assert_eq!(v.count_neighbors8(Coord::new(0, 0), |&alive| alive), 2);
+        assert_eq!(v.count_neighbors8(Coord::new(2, 0), |&alive| alive), 2);
+        assert_eq!(v.count_neighbors8(Coord::new(1, 1), |&alive| alive), 6);
+        assert_eq!(v.count_neighbors8(Coord::new(1, 1), |&alive| !alive), 2);
+    }
+
+    #[test]
+    fn test_life_step() {
+        let (o, x) = (false, true);
+
+        #[rustfmt::skip]
+        let horizontal = Vec2D::from_vec(Size::new(3, 3), vec![
+            o, o, o,
+            x, x, x,
+            o, o, o,
+        ]).unwrap();
+        #[rustfmt::skip]
+        let vertical = Vec2D::from_vec(Size::new(3, 3), vec![
+            o, x, o,
+            o, x, o,
+            o, x, o,
+        ]).unwrap();
+        assert_eq!(horizontal.life_step(), vertical);
+        assert_eq!(vertical.life_step(), horizontal);
+
+        #[rustfmt::skip]
+        let block = Vec2D::from_vec(Size::new(4, 4), vec![
+            o, o, o, o,
+            o, x, x, o,
+            o, x, x, o,
+            o, o, o, o,
+        ]).unwrap();
+        assert_eq!(block.life_step(), block);
+    }
 }